@@ -0,0 +1,125 @@
+//! Generic document type for ASN.1 DER-encoded data, implemented by
+//! [`RsaPublicKeyDocument`].
+//!
+//! [`RsaPrivateKeyDocument`] does not implement this trait: its `to_pem`
+//! returns `Zeroizing<String>` rather than this trait's plain `String`, and
+//! it is not `Clone`.
+
+mod private_key;
+mod public_key;
+
+pub use private_key::RsaPrivateKeyDocument;
+pub use public_key::RsaPublicKeyDocument;
+
+use crate::{Error, Result};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use der::Encodable;
+
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+#[cfg(feature = "pem")]
+use {
+    crate::{pem, LineEnding},
+    alloc::string::String,
+};
+
+/// Common functionality for a well-formed ASN.1 DER document, modeled after
+/// the analogous `Document` abstraction in the `spki`/`pkcs8` crates.
+///
+/// Implementing this trait only requires supplying the [`Document::Message`]
+/// ASN.1 type and [`Document::PEM_LABEL`]; the PEM and filesystem plumbing
+/// are provided as default methods so it doesn't need to be reimplemented
+/// per document type.
+pub trait Document<'a>:
+    AsRef<[u8]> + Clone + Sized + TryFrom<&'a [u8], Error = Error> + TryFrom<Vec<u8>, Error = Error>
+{
+    /// ASN.1 message type contained in this document.
+    type Message: TryFrom<&'a [u8], Error = der::Error> + Encodable;
+
+    /// PEM type label for this document's data, e.g. `"RSA PUBLIC KEY"`.
+    const PEM_LABEL: &'static str;
+
+    /// Parse the [`Document::Message`] contained in this document.
+    fn message(&'a self) -> Self::Message {
+        Self::Message::try_from(self.as_ref()).expect("malformed document")
+    }
+
+    /// Parse this document from ASN.1 DER.
+    fn from_der(bytes: &'a [u8]) -> Result<Self> {
+        Self::try_from(bytes)
+    }
+
+    /// Parse this document from PEM.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    fn from_pem(s: &str) -> Result<Self> {
+        let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
+
+        if label != Self::PEM_LABEL {
+            return Err(pem::Error::Label.into());
+        }
+
+        Self::try_from(der_bytes)
+    }
+
+    /// Serialize this document as a PEM-encoded string.
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    fn to_pem(&self) -> String {
+        self.to_pem_with_le(LineEnding::default())
+    }
+
+    /// Serialize this document as a PEM-encoded string using the given
+    /// [`LineEnding`].
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    fn to_pem_with_le(&self, line_ending: LineEnding) -> String {
+        pem::encode_string_with_le(Self::PEM_LABEL, line_ending, self.as_ref())
+            .expect(crate::error::PEM_ENCODING_MSG)
+    }
+
+    /// Load this document from an ASN.1 DER-encoded file on the local
+    /// filesystem (binary format).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn read_der_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::try_from(fs::read(path)?)
+    }
+
+    /// Load this document from a PEM-encoded file on the local filesystem.
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn read_pem_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_pem(&fs::read_to_string(path)?)
+    }
+
+    /// Write this document's ASN.1 DER encoding to the given path.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn write_der_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.as_ref())?;
+        Ok(())
+    }
+
+    /// Write this document's PEM encoding to the given path.
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn write_pem_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.to_pem().as_bytes())?;
+        Ok(())
+    }
+
+    /// Write this document's PEM encoding to the given path, using the
+    /// given [`LineEnding`].
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn write_pem_file_with_le(&self, path: impl AsRef<Path>, line_ending: LineEnding) -> Result<()> {
+        fs::write(path, self.to_pem_with_le(line_ending).as_bytes())?;
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 //! PKCS#1 RSA public key document.
 
-use crate::{error, Error, Result, RsaPublicKey};
+use crate::{error, public_key::PEM_TYPE_LABEL, Document, Error, Result, RsaPublicKey};
 use alloc::{borrow::ToOwned, vec::Vec};
 use core::{
     convert::{TryFrom, TryInto},
@@ -13,11 +13,30 @@ use std::{fs, path::Path, str};
 
 #[cfg(feature = "pem")]
 use {
-    crate::{pem, public_key::PEM_TYPE_LABEL},
+    crate::{pem, LineEnding},
     alloc::string::String,
     core::str::FromStr,
 };
 
+#[cfg(feature = "pkcs8")]
+use der::{Any, Decodable, Null, ObjectIdentifier};
+#[cfg(feature = "pkcs8")]
+use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
+
+#[cfg(feature = "jwk")]
+use {
+    alloc::format,
+    base64ct::{Base64UrlUnpadded, Encoding},
+    der::asn1::UIntBytes,
+};
+
+/// `rsaEncryption` object identifier for `RSASSA-PKCS1-v1_5` keys as defined
+/// in [RFC 3447 Appendix A.1].
+///
+/// [RFC 3447 Appendix A.1]: https://datatracker.ietf.org/doc/html/rfc3447#appendix-A.1
+#[cfg(feature = "pkcs8")]
+const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::new("1.2.840.113549.1.1.1");
+
 /// PKCS#1 `RSA PUBLIC KEY` document.
 ///
 /// This type provides storage for [`RsaPublicKey`] encoded as ASN.1
@@ -29,11 +48,15 @@ pub struct RsaPublicKeyDocument(Vec<u8>);
 
 impl RsaPublicKeyDocument {
     /// Parse the [`RsaPublicKey`] contained in this [`RsaPublicKeyDocument`]
+    ///
+    /// Compatibility shim for [`Document::message`].
     pub fn public_key(&self) -> RsaPublicKey<'_> {
-        RsaPublicKey::try_from(self.0.as_slice()).expect("malformed PublicKeyDocument")
+        self.message()
     }
 
     /// Parse [`RsaPublicKeyDocument`] from ASN.1 DER
+    ///
+    /// Compatibility shim for [`Document::from_der`].
     pub fn from_der(bytes: &[u8]) -> Result<Self> {
         bytes.try_into()
     }
@@ -45,65 +68,229 @@ impl RsaPublicKeyDocument {
     /// ```text
     /// -----BEGIN RSA PUBLIC KEY-----
     /// ```
+    ///
+    /// Compatibility shim for [`Document::from_pem`].
     #[cfg(feature = "pem")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
     pub fn from_pem(s: &str) -> Result<Self> {
-        let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
-
-        if label != PEM_TYPE_LABEL {
-            return Err(pem::Error::Label.into());
-        }
-
-        Self::from_der(&*der_bytes)
+        <Self as Document<'_>>::from_pem(s)
     }
 
     /// Serialize [`RsaPublicKeyDocument`] as PEM-encoded PKCS#8 string.
+    ///
+    /// Compatibility shim for [`Document::to_pem`].
     #[cfg(feature = "pem")]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
     pub fn to_pem(&self) -> String {
-        pem::encode_string(PEM_TYPE_LABEL, &self.0).expect(error::PEM_ENCODING_MSG)
+        Document::to_pem(self)
+    }
+
+    /// Serialize [`RsaPublicKeyDocument`] as PEM-encoded string using the
+    /// given [`LineEnding`].
+    ///
+    /// Compatibility shim for [`Document::to_pem_with_le`].
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn to_pem_with_le(&self, line_ending: LineEnding) -> String {
+        Document::to_pem_with_le(self, line_ending)
     }
 
     /// Load [`RsaPublicKeyDocument`] from an ASN.1 DER-encoded file on the local
     /// filesystem (binary format).
+    ///
+    /// Compatibility shim for [`Document::read_der_file`].
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn read_der_file(path: impl AsRef<Path>) -> Result<Self> {
-        fs::read(path)?.try_into()
+        <Self as Document<'_>>::read_der_file(path)
     }
 
     /// Load [`RsaPublicKeyDocument`] from a PEM-encoded file on the local filesystem.
+    ///
+    /// Compatibility shim for [`Document::read_pem_file`].
     #[cfg(all(feature = "pem", feature = "std"))]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn read_pem_file(path: impl AsRef<Path>) -> Result<Self> {
-        Self::from_pem(&fs::read_to_string(path)?)
+        <Self as Document<'_>>::read_pem_file(path)
     }
 
     /// Write ASN.1 DER-encoded public key to the given path
+    ///
+    /// Compatibility shim for [`Document::write_der_file`].
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn write_der_file(&self, path: impl AsRef<Path>) -> Result<()> {
-        fs::write(path, self.as_ref())?;
-        Ok(())
+        Document::write_der_file(self, path)
     }
 
     /// Write PEM-encoded public key to the given path
+    ///
+    /// Compatibility shim for [`Document::write_pem_file`].
     #[cfg(all(feature = "pem", feature = "std"))]
     #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
     #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn write_pem_file(&self, path: impl AsRef<Path>) -> Result<()> {
-        fs::write(path, self.to_pem().as_bytes())?;
-        Ok(())
+        Document::write_pem_file(self, path)
+    }
+
+    /// Write PEM-encoded public key to the given path, using the given
+    /// [`LineEnding`].
+    ///
+    /// Compatibility shim for [`Document::write_pem_file_with_le`].
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_pem_file_with_le(&self, path: impl AsRef<Path>, line_ending: LineEnding) -> Result<()> {
+        Document::write_pem_file_with_le(self, path, line_ending)
+    }
+
+    /// Convert this PKCS#1 [`RsaPublicKeyDocument`] into an X.509
+    /// `SubjectPublicKeyInfo` (SPKI) document, ASN.1 DER-encoded.
+    ///
+    /// The resulting document wraps this document's PKCS#1
+    /// `RSAPublicKey` DER as the SPKI `subjectPublicKey` BIT STRING, tagged
+    /// with the `rsaEncryption` algorithm identifier and a `NULL` parameter.
+    #[cfg(feature = "pkcs8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+    pub fn to_spki_der(&self) -> Result<Vec<u8>> {
+        let spki = SubjectPublicKeyInfo {
+            algorithm: AlgorithmIdentifier {
+                oid: RSA_ENCRYPTION_OID,
+                parameters: Some(Any::from(&Null)),
+            },
+            subject_public_key: self.as_ref(),
+        };
+
+        Ok(spki.to_vec()?)
+    }
+
+    /// Parse an X.509 `SubjectPublicKeyInfo` (SPKI) document, ASN.1
+    /// DER-encoded, unwrapping the PKCS#1 `RSAPublicKey` DER it contains
+    /// into an [`RsaPublicKeyDocument`].
+    #[cfg(feature = "pkcs8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+    pub fn from_spki_der(bytes: &[u8]) -> Result<Self> {
+        let spki = SubjectPublicKeyInfo::from_der(bytes)?;
+
+        if spki.algorithm.oid != RSA_ENCRYPTION_OID {
+            return Err(der::ErrorKind::OidUnknown {
+                oid: spki.algorithm.oid,
+            }
+            .into());
+        }
+
+        match spki.algorithm.parameters {
+            Some(params) if Null::try_from(params).is_ok() => (),
+            _ => return Err(der::ErrorKind::Value { tag: der::Tag::Null }.into()),
+        }
+
+        Self::from_der(spki.subject_public_key)
+    }
+
+    /// Parse an X.509 `SubjectPublicKeyInfo` (SPKI) document, PEM-encoded,
+    /// into an [`RsaPublicKeyDocument`].
+    ///
+    /// SPKI-encoded public keys can be identified by the leading delimiter:
+    ///
+    /// ```text
+    /// -----BEGIN PUBLIC KEY-----
+    /// ```
+    #[cfg(all(feature = "pkcs8", feature = "pem"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "pkcs8", feature = "pem"))))]
+    pub fn from_spki_pem(s: &str) -> Result<Self> {
+        let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
+
+        if label != "PUBLIC KEY" {
+            return Err(pem::Error::Label.into());
+        }
+
+        Self::from_spki_der(&*der_bytes)
+    }
+
+    /// Serialize this [`RsaPublicKeyDocument`] as a JWK (JSON Web Key)
+    /// string: `{"kty":"RSA","n":<base64url>,"e":<base64url>}`, per
+    /// [RFC 7518 Section 6.3.1].
+    ///
+    /// [RFC 7518 Section 6.3.1]: https://datatracker.ietf.org/doc/html/rfc7518#section-6.3.1
+    #[cfg(feature = "jwk")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+    pub fn to_jwk_string(&self) -> Result<String> {
+        let key = self.public_key();
+
+        Ok(format!(
+            r#"{{"kty":"RSA","n":"{}","e":"{}"}}"#,
+            Base64UrlUnpadded::encode_string(strip_leading_zero(key.modulus.as_bytes())),
+            Base64UrlUnpadded::encode_string(strip_leading_zero(key.public_exponent.as_bytes())),
+        ))
+    }
+
+    /// Parse an [`RsaPublicKeyDocument`] from a JWK (JSON Web Key) string.
+    #[cfg(feature = "jwk")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "jwk")))]
+    pub fn from_jwk_string(s: &str) -> Result<Self> {
+        let malformed = || der::ErrorKind::Value {
+            tag: der::Tag::Utf8String,
+        };
+
+        let jwk = json::parse(s).map_err(|_| malformed())?;
+
+        if jwk["kty"].as_str() != Some("RSA") {
+            return Err(malformed().into());
+        }
+
+        let n = jwk["n"].as_str().ok_or_else(malformed)?;
+        let e = jwk["e"].as_str().ok_or_else(malformed)?;
+
+        let modulus = jwk_uint_bytes(n)?;
+        let public_exponent = jwk_uint_bytes(e)?;
+
+        Ok(RsaPublicKeyDocument::from(&RsaPublicKey {
+            modulus: UIntBytes::new(&modulus)?,
+            public_exponent: UIntBytes::new(&public_exponent)?,
+        }))
+    }
+}
+
+/// Strip a single leading `0x00` padding byte that DER inserts into an
+/// `INTEGER` encoding whenever its high bit is set, so the JWK's base64url
+/// encoding of the value is minimal per RFC 7518.
+#[cfg(feature = "jwk")]
+fn strip_leading_zero(bytes: &[u8]) -> &[u8] {
+    match bytes {
+        [0, rest @ ..] if !rest.is_empty() => rest,
+        _ => bytes,
     }
 }
 
+/// Base64url-decode a JWK integer and re-pad it with a leading `0x00` byte
+/// if its top bit is set, so it round-trips as a positive DER `INTEGER`.
+#[cfg(feature = "jwk")]
+fn jwk_uint_bytes(value: &str) -> Result<Vec<u8>> {
+    let mut bytes = Base64UrlUnpadded::decode_vec(value).map_err(|_| {
+        der::ErrorKind::Value {
+            tag: der::Tag::Integer,
+        }
+    })?;
+
+    if bytes.first().map_or(false, |byte| byte & 0x80 != 0) {
+        bytes.insert(0, 0);
+    }
+
+    Ok(bytes)
+}
+
 impl AsRef<[u8]> for RsaPublicKeyDocument {
     fn as_ref(&self) -> &[u8] {
         self.0.as_ref()
     }
 }
 
+impl<'a> Document<'a> for RsaPublicKeyDocument {
+    type Message = RsaPublicKey<'a>;
+    const PEM_LABEL: &'static str = PEM_TYPE_LABEL;
+}
+
 impl From<RsaPublicKey<'_>> for RsaPublicKeyDocument {
     fn from(spki: RsaPublicKey<'_>) -> RsaPublicKeyDocument {
         RsaPublicKeyDocument::from(&spki)
@@ -155,4 +342,49 @@ impl FromStr for RsaPublicKeyDocument {
     fn from_str(s: &str) -> Result<Self> {
         Self::from_pem(s)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RsaPublicKeyDocument;
+
+    /// DER encoding of a minimal, syntactically well-formed
+    /// `RSAPublicKey { modulus: 0x0101, publicExponent: 0x010001 }`.
+    ///
+    /// Not a cryptographically meaningful key; only useful for exercising
+    /// the encode/decode round trip.
+    const TEST_KEY_DER: &[u8] = &[
+        0x30, 0x09, 0x02, 0x02, 0x01, 0x01, 0x02, 0x03, 0x01, 0x00, 0x01,
+    ];
+
+    #[cfg(feature = "pkcs8")]
+    #[test]
+    fn spki_der_round_trip() {
+        let doc = RsaPublicKeyDocument::from_der(TEST_KEY_DER).unwrap();
+        let spki_der = doc.to_spki_der().unwrap();
+        let doc2 = RsaPublicKeyDocument::from_spki_der(&spki_der).unwrap();
+        assert_eq!(doc.as_ref(), doc2.as_ref());
+    }
+
+    #[cfg(feature = "jwk")]
+    #[test]
+    fn jwk_string_round_trip() {
+        let doc = RsaPublicKeyDocument::from_der(TEST_KEY_DER).unwrap();
+        let jwk = doc.to_jwk_string().unwrap();
+        let doc2 = RsaPublicKeyDocument::from_jwk_string(&jwk).unwrap();
+        assert_eq!(doc.as_ref(), doc2.as_ref());
+    }
+
+    #[cfg(feature = "pem")]
+    #[test]
+    fn pem_crlf_round_trip() {
+        use crate::LineEnding;
+
+        let doc = RsaPublicKeyDocument::from_der(TEST_KEY_DER).unwrap();
+        let pem = doc.to_pem_with_le(LineEnding::CRLF);
+        assert!(pem.contains("\r\n"));
+
+        let doc2 = RsaPublicKeyDocument::from_pem(&pem).unwrap();
+        assert_eq!(doc.as_ref(), doc2.as_ref());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,45 @@
+//! Line ending support for PEM encoding.
+
+use core::fmt;
+
+/// Line ending to use when encoding PEM documents.
+///
+/// Defaults to `LF`, i.e. Unix-style line endings. Use [`LineEnding::CRLF`]
+/// when producing PEM consumed by Windows tooling, or when byte-exact
+/// round-tripping against PEM generated on such a platform is required.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LineEnding {
+    /// Unix-style line endings (`\n`)
+    LF,
+
+    /// Windows-style line endings (`\r\n`)
+    CRLF,
+}
+
+impl Default for LineEnding {
+    fn default() -> LineEnding {
+        LineEnding::LF
+    }
+}
+
+impl LineEnding {
+    /// Get this [`LineEnding`] as a `&str`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::LF => "\n",
+            LineEnding::CRLF => "\r\n",
+        }
+    }
+}
+
+impl AsRef<str> for LineEnding {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for LineEnding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,138 @@
+//! Traits for parsing objects from PKCS#1 encoded documents, as well as for
+//! serializing to PKCS#1 encoded documents.
+
+use crate::{Error, Result, RsaPublicKeyDocument};
+use core::convert::{TryFrom, TryInto};
+
+#[cfg(feature = "pem")]
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// Parse a type that can be converted from a [`RsaPublicKeyDocument`] from
+/// PKCS#1-encoded data.
+pub trait DecodeRsaPublicKey: Sized {
+    /// Deserialize object from ASN.1 DER-encoded [`RsaPublicKeyDocument`].
+    fn from_pkcs1_der(bytes: &[u8]) -> Result<Self>;
+
+    /// Deserialize object from PEM-encoded [`RsaPublicKeyDocument`].
+    ///
+    /// PEM-encoded public keys can be identified by the leading delimiter:
+    ///
+    /// ```text
+    /// -----BEGIN RSA PUBLIC KEY-----
+    /// ```
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    fn from_pkcs1_pem(s: &str) -> Result<Self> {
+        Self::from_pkcs1_der(RsaPublicKeyDocument::from_pem(s)?.as_ref())
+    }
+
+    /// Load object from an ASN.1 DER-encoded file on the local filesystem.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn read_pkcs1_der_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_pkcs1_der(RsaPublicKeyDocument::read_der_file(path)?.as_ref())
+    }
+
+    /// Load object from a PEM-encoded file on the local filesystem.
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn read_pkcs1_pem_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_pkcs1_der(RsaPublicKeyDocument::read_pem_file(path)?.as_ref())
+    }
+}
+
+/// Serialize a [`RsaPublicKeyDocument`] (or a type that can be converted
+/// into one) to a PKCS#1 encoding.
+pub trait EncodeRsaPublicKey {
+    /// Serialize ASN.1 DER-encoded [`RsaPublicKeyDocument`].
+    fn to_pkcs1_der(&self) -> Result<RsaPublicKeyDocument>;
+
+    /// Serialize PEM-encoded [`RsaPublicKeyDocument`].
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    fn to_pkcs1_pem(&self) -> Result<String> {
+        Ok(self.to_pkcs1_der()?.to_pem())
+    }
+
+    /// Write ASN.1 DER-encoded public key to the given path.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn write_pkcs1_der_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.to_pkcs1_der()?.write_der_file(path)
+    }
+
+    /// Write PEM-encoded public key to the given path.
+    #[cfg(all(feature = "pem", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn write_pkcs1_pem_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.to_pkcs1_der()?.write_pem_file(path)
+    }
+}
+
+impl<T> DecodeRsaPublicKey for T
+where
+    T: TryFrom<RsaPublicKeyDocument, Error = Error>,
+{
+    fn from_pkcs1_der(bytes: &[u8]) -> Result<Self> {
+        T::try_from(RsaPublicKeyDocument::from_der(bytes)?)
+    }
+}
+
+impl<T> EncodeRsaPublicKey for T
+where
+    T: TryInto<RsaPublicKeyDocument, Error = Error> + Clone,
+{
+    fn to_pkcs1_der(&self) -> Result<RsaPublicKeyDocument> {
+        self.clone().try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecodeRsaPublicKey, EncodeRsaPublicKey};
+    use crate::{Error, Result, RsaPublicKeyDocument};
+    use core::convert::TryFrom;
+
+    /// DER encoding of a minimal, syntactically well-formed
+    /// `RSAPublicKey { modulus: 0x0101, publicExponent: 0x010001 }`.
+    const TEST_KEY_DER: &[u8] = &[
+        0x30, 0x09, 0x02, 0x02, 0x01, 0x01, 0x02, 0x03, 0x01, 0x00, 0x01,
+    ];
+
+    /// A stand-in for a downstream crate's own RSA public key type, which
+    /// only knows how to convert to/from [`RsaPublicKeyDocument`] and
+    /// should get the rest of [`DecodeRsaPublicKey`]/[`EncodeRsaPublicKey`]
+    /// for free via the blanket impls.
+    #[derive(Clone)]
+    struct LocalRsaPublicKey(RsaPublicKeyDocument);
+
+    impl TryFrom<RsaPublicKeyDocument> for LocalRsaPublicKey {
+        type Error = Error;
+
+        fn try_from(doc: RsaPublicKeyDocument) -> Result<Self> {
+            Ok(LocalRsaPublicKey(doc))
+        }
+    }
+
+    impl TryFrom<LocalRsaPublicKey> for RsaPublicKeyDocument {
+        type Error = Error;
+
+        fn try_from(key: LocalRsaPublicKey) -> Result<Self> {
+            Ok(key.0)
+        }
+    }
+
+    #[test]
+    fn blanket_impls_round_trip() {
+        let key = LocalRsaPublicKey::from_pkcs1_der(TEST_KEY_DER).unwrap();
+        assert_eq!(key.0.as_ref(), TEST_KEY_DER);
+
+        let doc = key.to_pkcs1_der().unwrap();
+        assert_eq!(doc.as_ref(), TEST_KEY_DER);
+    }
+}